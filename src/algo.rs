@@ -0,0 +1,526 @@
+//! Reusable graph traversal algorithms -- breadth-first search, depth-first
+//! search, and topological sort -- built only on the read-only `nav_types`
+//! traits, so they run on any graph backing without requiring mutable
+//! access.
+//!
+//! Traversal state is tracked with the classic three-color scheme (White,
+//! Gray, Black) in an external map keyed by `GraphTypes::VertexIndexType`,
+//! rather than on the graph itself. This is what lets these algorithms work
+//! on read-only borrows and on graphs that don't carry mutable color fields,
+//! and lets several traversals run concurrently over the same borrow with
+//! separate color maps.
+
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::ops::Add;
+
+use super::{GraphTypes, IncidenceGraph};
+use super::nav_types::{NavTypes, Vertex, Edge, OutEdgeCollection};
+use super::property::{MutablePropertyMap, PropertyMap};
+
+/// The classic three-color vertex-coloring scheme used to track traversal
+/// state without mutating the graph.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Color {
+    /// Not yet discovered.
+    White,
+    /// Discovered, but not finished: on the traversal's stack or queue.
+    Gray,
+    /// Finished: every vertex reachable from it has also been discovered.
+    Black,
+}
+
+/// External color storage keyed by `GraphTypes::VertexIndexType`. Vertices
+/// absent from the map are implicitly `Color::White`.
+pub type ColorMap<T> = HashMap<<T as GraphTypes>::VertexIndexType, Color>;
+
+fn color_of<T: GraphTypes>(colors: &ColorMap<T>, index: T::VertexIndexType) -> Color {
+    colors.get(&index).copied().unwrap_or(Color::White)
+}
+
+/// Hooks invoked at key points of a traversal. Default implementations are
+/// no-ops, so callers only implement the events they care about.
+///
+/// Implementing `examine_edge` to check whether the target vertex is
+/// `Color::Gray` (via an external `ColorMap`) detects back edges during a
+/// depth-first search, i.e. cycles; labeling `discover_vertex`/
+/// `finish_vertex` with a counter supports component or discovery/finish
+/// time labeling.
+pub trait Visitor<'a, T: NavTypes<'a>> {
+    /// Called once for every edge examined, regardless of the color of its
+    /// target vertex.
+    fn examine_edge(&mut self, _edge: &T::NavEdge) {}
+
+    /// Called when `examine_edge` finds the target vertex still White,
+    /// making `edge` part of the traversal's spanning tree/forest.
+    fn tree_edge(&mut self, _edge: &T::NavEdge) {}
+
+    /// Called the first time a vertex is discovered, i.e. turned Gray.
+    fn discover_vertex(&mut self, _vertex: &T::NavVertex) {}
+
+    /// Called when a vertex is finished, i.e. turned Black.
+    fn finish_vertex(&mut self, _vertex: &T::NavVertex) {}
+}
+
+/// Breadth-first search from `start`, invoking `visitor`'s hooks as vertices
+/// and edges are examined. Vertices already Gray or Black in `colors` are
+/// treated as already visited, so a caller can run several searches over the
+/// same `colors` map to explore a graph component by component.
+pub fn breadth_first_search<'a, T, V>(start: T::NavVertex, colors: &mut ColorMap<T>, visitor: &mut V)
+where
+    T: NavTypes<'a>,
+    V: Visitor<'a, T>,
+    T::TraversalCategory: IncidenceGraph,
+{
+    if color_of::<T>(colors, start.index()) != Color::White {
+        return;
+    }
+
+    let mut queue = VecDeque::new();
+    colors.insert(start.index(), Color::Gray);
+    visitor.discover_vertex(&start);
+    queue.push_back(start);
+
+    while let Some(vertex) = queue.pop_front() {
+        for edge in vertex.out_edges().iter() {
+            visitor.examine_edge(&edge);
+            let target = edge.target();
+            if color_of::<T>(colors, target.index()) == Color::White {
+                visitor.tree_edge(&edge);
+                colors.insert(target.index(), Color::Gray);
+                visitor.discover_vertex(&target);
+                queue.push_back(target);
+            }
+        }
+        colors.insert(vertex.index(), Color::Black);
+        visitor.finish_vertex(&vertex);
+    }
+}
+
+/// Depth-first search from `start`, invoking `visitor`'s hooks as vertices
+/// and edges are examined. Vertices already Gray or Black in `colors` are
+/// treated as already visited.
+pub fn depth_first_search<'a, T, V>(start: T::NavVertex, colors: &mut ColorMap<T>, visitor: &mut V)
+where
+    T: NavTypes<'a>,
+    V: Visitor<'a, T>,
+    T::TraversalCategory: IncidenceGraph,
+{
+    if color_of::<T>(colors, start.index()) != Color::White {
+        return;
+    }
+
+    colors.insert(start.index(), Color::Gray);
+    visitor.discover_vertex(&start);
+
+    for edge in start.out_edges().iter() {
+        visitor.examine_edge(&edge);
+        let target = edge.target();
+        if color_of::<T>(colors, target.index()) == Color::White {
+            visitor.tree_edge(&edge);
+            depth_first_search(target, colors, visitor);
+        }
+    }
+
+    colors.insert(start.index(), Color::Black);
+    visitor.finish_vertex(&start);
+}
+
+/// Topological sort of the vertices reachable from `roots`, via depth-first
+/// search. Returns the vertices in an order where every edge points from an
+/// earlier vertex to a later one. If the graph has a cycle, returns the back
+/// edge that closes it rather than an order, since no such order exists.
+pub fn topological_sort<'a, T>(
+    roots: impl IntoIterator<Item = T::NavVertex>,
+    colors: &mut ColorMap<T>,
+) -> Result<Vec<T::NavVertex>, T::NavEdge>
+where
+    T: NavTypes<'a>,
+    T::TraversalCategory: IncidenceGraph,
+{
+    fn visit<'a, T: NavTypes<'a>>(
+        vertex: T::NavVertex,
+        colors: &mut ColorMap<T>,
+        order: &mut Vec<T::NavVertex>,
+    ) -> Result<(), T::NavEdge>
+    where
+        T::TraversalCategory: IncidenceGraph,
+    {
+        colors.insert(vertex.index(), Color::Gray);
+
+        for edge in vertex.out_edges().iter() {
+            let target = edge.target();
+            match color_of::<T>(colors, target.index()) {
+                Color::White => visit::<T>(target, colors, order)?,
+                Color::Gray => return Err(edge),
+                Color::Black => {}
+            }
+        }
+
+        colors.insert(vertex.index(), Color::Black);
+        order.push(vertex);
+        Ok(())
+    }
+
+    let mut order = Vec::new();
+    for root in roots {
+        if color_of::<T>(colors, root.index()) == Color::White {
+            visit::<T>(root, colors, &mut order)?;
+        }
+    }
+    order.reverse();
+    Ok(order)
+}
+
+/// A pending relaxation: `vertex_index` was reached with total distance
+/// `distance` via `via_edge` (`None` only for `start` itself). Ordered
+/// solely by `distance`, smallest first, so that `BinaryHeap` (a max-heap)
+/// can be used as Dijkstra's min-priority queue; `T::VertexIndexType` need
+/// not itself be `Ord` for this to work, and a vertex may appear in the
+/// queue more than once; the first time it is popped still not finished is
+/// the one bearing its true shortest distance, and later, staler entries
+/// for it are discarded at pop time.
+struct QueueEntry<Weight, NavEdge> {
+    distance: Weight,
+    vertex_index: usize,
+    via_edge: Option<NavEdge>,
+}
+
+impl<Weight: PartialEq, NavEdge> PartialEq for QueueEntry<Weight, NavEdge> {
+    fn eq(&self, other: &Self) -> bool {
+        self.distance == other.distance
+    }
+}
+
+impl<Weight: PartialEq, NavEdge> Eq for QueueEntry<Weight, NavEdge> {}
+
+impl<Weight: PartialOrd, NavEdge> PartialOrd for QueueEntry<Weight, NavEdge> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<Weight: PartialOrd, NavEdge> Ord for QueueEntry<Weight, NavEdge> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.distance.partial_cmp(&self.distance).unwrap_or(Ordering::Equal)
+    }
+}
+
+/// Dijkstra-style shortest paths from `start`, relaxing edges via property
+/// maps rather than fields on the graph itself: `distances` and
+/// `predecessors` are written with the shortest distance and tree edge
+/// discovered for every reached vertex (`start` gets a distance of
+/// `Weight::default()` and no predecessor). Because nothing here mutates the
+/// graph, several such analyses (e.g. with different weight maps) can run
+/// concurrently over the same read-only borrow.
+///
+/// `distances` and `predecessors` are keyed by `GraphTypes::VertexIndexType`
+/// converted `Into<usize>`, rather than by `VertexDescriptor`, since that is
+/// the stable identity `nav_types::Vertex` already exposes via `index()`.
+/// `weights` is keyed by the `(source index, target index)` pair of the
+/// edge being relaxed, which identifies it uniquely as long as the graph's
+/// `EdgeParallelCategory` is `DisallowParallelEdges`.
+pub fn dijkstra_shortest_paths<'a, T, W, D, P, Weight>(
+    start: T::NavVertex,
+    weights: &W,
+    distances: &mut D,
+    predecessors: &mut P,
+) where
+    T: NavTypes<'a>,
+    Weight: Copy + PartialOrd + Add<Output = Weight> + Default,
+    W: PropertyMap<(usize, usize), Weight>,
+    D: MutablePropertyMap<usize, Weight>,
+    P: MutablePropertyMap<usize, T::NavEdge>,
+    T::TraversalCategory: IncidenceGraph,
+{
+    let mut vertices: HashMap<usize, T::NavVertex> = HashMap::new();
+    let mut finished: HashSet<usize> = HashSet::new();
+
+    let start_index = start.index().into();
+    vertices.insert(start_index, start);
+
+    let mut queue = BinaryHeap::new();
+    queue.push(QueueEntry { distance: Weight::default(), vertex_index: start_index, via_edge: None });
+
+    while let Some(QueueEntry { distance, vertex_index, via_edge }) = queue.pop() {
+        if finished.contains(&vertex_index) {
+            continue;
+        }
+        finished.insert(vertex_index);
+        distances.put(vertex_index, distance);
+        if let Some(edge) = via_edge {
+            predecessors.put(vertex_index, edge);
+        }
+
+        let vertex = vertices.remove(&vertex_index).expect("queued vertex not recorded");
+        for edge in vertex.out_edges().iter() {
+            let target = edge.target();
+            let target_index = target.index().into();
+            if finished.contains(&target_index) {
+                continue;
+            }
+            let candidate = distance + *weights.get((vertex_index, target_index));
+            vertices.insert(target_index, target);
+            queue.push(QueueEntry { distance: candidate, vertex_index: target_index, via_edge: Some(edge) });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        BoundedIterator, Directed, DisallowParallelEdges, GraphTypes, IncidenceGraph,
+    };
+    use crate::property::ExternalPropertyMap;
+
+    /// A plain adjacency-list graph used only to exercise `algo` against a
+    /// real `NavTypes` implementation. `out_adjacency[v]` holds the indices
+    /// (into `edges`) of `v`'s outgoing edges.
+    #[derive(Debug)]
+    struct TestGraph {
+        vertex_data: Vec<&'static str>,
+        edges: Vec<(usize, usize, u32)>,
+        out_adjacency: Vec<Vec<usize>>,
+    }
+
+    impl TestGraph {
+        fn new(vertex_data: Vec<&'static str>, edges: Vec<(usize, usize, u32)>) -> Self {
+            let mut out_adjacency = vec![Vec::new(); vertex_data.len()];
+            for (edge_index, &(source, _, _)) in edges.iter().enumerate() {
+                out_adjacency[source].push(edge_index);
+            }
+            TestGraph { vertex_data, edges, out_adjacency }
+        }
+
+        fn vertex(&self, index: usize) -> TestVertex<'_> {
+            TestVertex { graph: self, index }
+        }
+    }
+
+    /// Marker for `TestGraph::TraversalCategory`: only outgoing edges are
+    /// enumerable, matching what `algo`'s traversals and Dijkstra need.
+    struct TestTraversal;
+    impl IncidenceGraph for TestTraversal {}
+
+    impl GraphTypes for TestGraph {
+        type VertexData = &'static str;
+        type EdgeData = u32;
+        type DirectedCategory = Directed;
+        type EdgeParallelCategory = DisallowParallelEdges;
+        type TraversalCategory = TestTraversal;
+        type VertexDescriptor = usize;
+        type EdgeDescriptor = usize;
+        type VertexIndexType = usize;
+        type DegreeSizeType = usize;
+    }
+
+    #[derive(Clone, Copy)]
+    struct TestVertex<'a> {
+        graph: &'a TestGraph,
+        index: usize,
+    }
+
+    #[derive(Clone, Copy, Debug)]
+    struct TestEdge<'a> {
+        graph: &'a TestGraph,
+        edge_index: usize,
+    }
+
+    struct TestOutEdges<'a> {
+        graph: &'a TestGraph,
+        vertex: usize,
+    }
+
+    struct TestEdgeIter<'a> {
+        graph: &'a TestGraph,
+        remaining: std::slice::Iter<'a, usize>,
+    }
+
+    impl<'a> Iterator for TestEdgeIter<'a> {
+        type Item = TestEdge<'a>;
+
+        fn next(&mut self) -> Option<TestEdge<'a>> {
+            self.remaining.next().map(|&edge_index| TestEdge { graph: self.graph, edge_index })
+        }
+    }
+
+    impl<'a> BoundedIterator<'a> for TestEdgeIter<'a> {}
+
+    impl<'a> crate::nav_types::NavTypes<'a> for TestGraph {
+        type NavVertex = TestVertex<'a>;
+        type NavEdge = TestEdge<'a>;
+        type NavInEdgeCollection = TestOutEdges<'a>;
+        type NavOutEdgeCollection = TestOutEdges<'a>;
+    }
+
+    impl<'a> crate::nav_types::Vertex<'a> for TestVertex<'a> {
+        type Types = TestGraph;
+
+        fn data(&self) -> &'a &'static str {
+            &self.graph.vertex_data[self.index]
+        }
+
+        fn index(&self) -> usize {
+            self.index
+        }
+
+        fn descriptor(&self) -> usize {
+            self.index
+        }
+
+        fn out_edges(&self) -> TestOutEdges<'a> {
+            TestOutEdges { graph: self.graph, vertex: self.index }
+        }
+
+        fn in_edges(&self) -> TestOutEdges<'a> {
+            unimplemented!("TestGraph::TraversalCategory does not implement BidirectionalGraph")
+        }
+    }
+
+    impl<'a> crate::nav_types::Edge<'a> for TestEdge<'a> {
+        type Types = TestGraph;
+
+        fn data(&self) -> &'a u32 {
+            &self.graph.edges[self.edge_index].2
+        }
+
+        fn descriptor(&self) -> usize {
+            self.edge_index
+        }
+
+        fn source(&self) -> TestVertex<'a> {
+            TestVertex { graph: self.graph, index: self.graph.edges[self.edge_index].0 }
+        }
+
+        fn target(&self) -> TestVertex<'a> {
+            TestVertex { graph: self.graph, index: self.graph.edges[self.edge_index].1 }
+        }
+    }
+
+    impl<'a> crate::nav_types::OutEdgeCollection<'a> for TestOutEdges<'a> {
+        type Types = TestGraph;
+        type Iter = TestEdgeIter<'a>;
+
+        fn len(&self) -> usize {
+            self.graph.out_adjacency[self.vertex].len()
+        }
+
+        fn source(&self) -> TestVertex<'a> {
+            TestVertex { graph: self.graph, index: self.vertex }
+        }
+
+        fn iter(&self) -> TestEdgeIter<'a> {
+            TestEdgeIter { graph: self.graph, remaining: self.graph.out_adjacency[self.vertex].iter() }
+        }
+    }
+
+    /// `TestGraph::TraversalCategory` doesn't implement `BidirectionalGraph`,
+    /// so this is never actually driven; it only exists to satisfy
+    /// `NavTypes::NavInEdgeCollection`'s bound.
+    impl<'a> crate::nav_types::InEdgeCollection<'a> for TestOutEdges<'a> {
+        type Types = TestGraph;
+        type Iter = TestEdgeIter<'a>;
+
+        fn len(&self) -> usize {
+            self.graph.out_adjacency[self.vertex].len()
+        }
+
+        fn target(&self) -> TestVertex<'a> {
+            TestVertex { graph: self.graph, index: self.vertex }
+        }
+
+        fn iter(&self) -> TestEdgeIter<'a> {
+            TestEdgeIter { graph: self.graph, remaining: self.graph.out_adjacency[self.vertex].iter() }
+        }
+    }
+
+    /// A->B, A->C, B->C, B->D, C->D, D->E, all forward, no cycle.
+    fn dag() -> TestGraph {
+        TestGraph::new(
+            vec!["A", "B", "C", "D", "E"],
+            vec![
+                (0, 1, 1),
+                (0, 2, 4),
+                (1, 2, 2),
+                (1, 3, 7),
+                (2, 3, 1),
+                (3, 4, 3),
+            ],
+        )
+    }
+
+    #[derive(Default)]
+    struct RecordingVisitor {
+        discovered: Vec<usize>,
+    }
+
+    impl<'a> Visitor<'a, TestGraph> for RecordingVisitor {
+        fn discover_vertex(&mut self, vertex: &TestVertex<'a>) {
+            self.discovered.push(vertex.index());
+        }
+    }
+
+    #[test]
+    fn breadth_first_search_discovers_every_reachable_vertex_once() {
+        let graph = dag();
+        let mut colors = ColorMap::<TestGraph>::new();
+        let mut visitor = RecordingVisitor::default();
+        breadth_first_search(graph.vertex(0), &mut colors, &mut visitor);
+        assert_eq!(visitor.discovered, vec![0, 1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn depth_first_search_discovers_every_reachable_vertex_once() {
+        let graph = dag();
+        let mut colors = ColorMap::<TestGraph>::new();
+        let mut visitor = RecordingVisitor::default();
+        depth_first_search(graph.vertex(0), &mut colors, &mut visitor);
+        assert_eq!(visitor.discovered, vec![0, 1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn topological_sort_orders_every_edge_forward() {
+        let graph = dag();
+        let mut colors = ColorMap::<TestGraph>::new();
+        let order = topological_sort::<TestGraph>(vec![graph.vertex(0)], &mut colors)
+            .expect("dag has no cycle");
+        let position: std::collections::HashMap<usize, usize> =
+            order.iter().enumerate().map(|(position, vertex)| (vertex.index(), position)).collect();
+        for &(source, target, _) in &graph.edges {
+            assert!(position[&source] < position[&target]);
+        }
+    }
+
+    #[test]
+    fn topological_sort_reports_a_back_edge_on_a_cycle() {
+        let graph = TestGraph::new(vec!["X", "Y"], vec![(0, 1, 1), (1, 0, 1)]);
+        let mut colors = ColorMap::<TestGraph>::new();
+        let result = topological_sort::<TestGraph>(vec![graph.vertex(0)], &mut colors);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn dijkstra_shortest_paths_finds_minimal_distances() {
+        let graph = dag();
+        let mut weights = ExternalPropertyMap::new();
+        for (edge_index, &(source, target, weight)) in graph.edges.iter().enumerate() {
+            let _ = edge_index;
+            weights.put((source, target), weight);
+        }
+        let mut distances = ExternalPropertyMap::new();
+        let mut predecessors = ExternalPropertyMap::new();
+        dijkstra_shortest_paths::<TestGraph, _, _, _, u32>(
+            graph.vertex(0),
+            &weights,
+            &mut distances,
+            &mut predecessors,
+        );
+
+        assert_eq!(*distances.get(0), 0);
+        assert_eq!(*distances.get(1), 1);
+        assert_eq!(*distances.get(2), 3);
+        assert_eq!(*distances.get(3), 4);
+        assert_eq!(*distances.get(4), 7);
+    }
+}
@@ -24,6 +24,62 @@ use std::iter::Iterator;
 
 pub mod nav_types;
 pub mod mut_types;
+pub mod algo;
+pub mod property;
+
+// Marker types and traits describing, at the type level, which structural
+// guarantees a graph implementation makes. This is a port of the category
+// taxonomy from Boost.Graph's `graph_traits`: rather than every algorithm
+// checking at runtime whether (say) in-edges are available, a graph's
+// `GraphTypes::TraversalCategory` either does or does not implement the
+// relevant marker trait, so unsupported operations are rejected at compile
+// time instead.
+
+/// Marker type for a graph whose edges are directed and for which only
+/// outgoing edges are efficiently enumerable.
+pub struct Directed;
+/// Marker type for a graph with no inherent edge direction.
+pub struct Undirected;
+/// Marker type for a directed graph that also efficiently enumerates
+/// incoming edges.
+pub struct Bidirectional;
+
+/// Implemented by the marker type in `GraphTypes::DirectedCategory`.
+pub trait DirectedCategory {}
+impl DirectedCategory for Directed {}
+impl DirectedCategory for Undirected {}
+impl DirectedCategory for Bidirectional {}
+
+/// Marker type for a graph that may hold more than one edge between the same
+/// pair of vertices.
+pub struct AllowParallelEdges;
+/// Marker type for a graph that enforces at most one edge between any given
+/// pair of vertices.
+pub struct DisallowParallelEdges;
+
+/// Implemented by the marker type in `GraphTypes::EdgeParallelCategory`.
+pub trait EdgeParallelCategory {}
+impl EdgeParallelCategory for AllowParallelEdges {}
+impl EdgeParallelCategory for DisallowParallelEdges {}
+
+/// Implemented by a `GraphTypes::TraversalCategory` that can enumerate the
+/// outgoing edges of a vertex. This is the baseline capability that
+/// `nav_types::Vertex::out_edges` and `mut_types::Vertex::out_edges` require.
+pub trait IncidenceGraph {}
+
+/// Implemented by a `GraphTypes::TraversalCategory` that can, in addition to
+/// `IncidenceGraph`, enumerate the incoming edges of a vertex. Graphs whose
+/// `TraversalCategory` does not implement this trait cannot offer
+/// `in_edges`.
+pub trait BidirectionalGraph: IncidenceGraph {}
+
+/// Implemented by a `GraphTypes::TraversalCategory` that can enumerate every
+/// vertex in the graph, independent of any particular vertex's edges.
+pub trait VertexListGraph {}
+
+/// Implemented by a `GraphTypes::TraversalCategory` that can enumerate every
+/// edge in the graph, independent of any particular vertex's edges.
+pub trait EdgeListGraph {}
 
 /// Base types that the graph defines a structure over.
 pub trait GraphTypes: Sized {
@@ -31,8 +87,38 @@ pub trait GraphTypes: Sized {
     type VertexData;
     /// The type of data at edges.
     type EdgeData;
+
+    /// Whether edges are `Directed`, `Undirected`, or `Bidirectional`.
+    type DirectedCategory: DirectedCategory;
+    /// Whether the graph allows parallel edges between the same pair of
+    /// vertices.
+    type EdgeParallelCategory: EdgeParallelCategory;
+    /// Which enumeration capabilities (incidence, bidirectional incidence,
+    /// vertex-list, edge-list) the backing graph implementation supports.
+    /// Algorithms bound their requirements on this type rather than on
+    /// `DirectedCategory`, since e.g. an undirected graph can still support
+    /// `BidirectionalGraph`-style in-edge enumeration.
+    type TraversalCategory;
+
+    /// A lightweight, `Copy` handle identifying a vertex, distinct from the
+    /// borrow-backed `nav_types::NavVertex`/`mut_types::MutVertex`. Unlike
+    /// those, a `VertexDescriptor` carries no borrow and so may be freely
+    /// copied, stored, and compared after the graph borrow that produced it
+    /// has ended.
+    type VertexDescriptor: Copy;
+    /// The edge counterpart of `VertexDescriptor`.
+    type EdgeDescriptor: Copy;
+
+    /// An integer type suitable for indexing into dense per-vertex storage
+    /// (e.g. a `Vec` of colors or distances), if the graph supports it. Also
+    /// usable as a `HashMap` key, so algorithms can track per-vertex state
+    /// externally rather than requiring the graph to carry it.
+    type VertexIndexType: Copy + Eq + std::hash::Hash + Into<usize>;
+    /// An integer type suitable for representing a vertex's degree.
+    type DegreeSizeType: Copy + Into<usize>;
 }
 
 /// When iterating over components of a graph structure, we need to give a name
 /// to the borrow of the underlying graph. This trait does so.
 pub trait BoundedIterator<'a>: Iterator { }
+
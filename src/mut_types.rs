@@ -2,14 +2,42 @@
 //! are backed by a mutable borrow of graph data, so multiple active
 //! (unborrowed) instances cannot exist.
 
-use super::{GraphTypes, BoundedIterator};
+use super::{GraphTypes, BoundedIterator, BidirectionalGraph, IncidenceGraph};
 use super::nav_types::NavTypes;
 
 pub trait MutTypes<'a>: NavTypes<'a> {
     type MutVertex: Vertex<'a, Types=Self>;
     type MutEdge: Edge<'a, Types=Self>;
     type MutInEdgeCollection: InEdgeCollection<'a, Types=Self>;
-    type MutOutEdgeCollection: OutEdgeCollection<'a, Types=Self>;
+    type MutOutEdgeCollection: MutableOutEdgeCollection<'a, Types=Self>;
+
+    /// A read-only reborrow of outgoing edges, parameterized by the lifetime
+    /// `'s` of whatever borrow of a `Vertex<'a>` produced it. This is the GAT
+    /// that lets `Vertex::out_edges` hand back a `nav_types::OutEdgeCollection`
+    /// tied to a fresh, shorter-lived borrow instead of to `'a` itself.
+    type NavOutEdges<'s>: super::nav_types::OutEdgeCollection<'s> where Self: 's;
+    /// The incoming-edge counterpart of `NavOutEdges`.
+    type NavInEdges<'s>: super::nav_types::InEdgeCollection<'s> where Self: 's;
+    /// A mutable reborrow of outgoing edges, parameterized the same way as
+    /// `NavOutEdges` but carrying a `mut_types::OutEdgeCollection` that holds
+    /// the vertex's borrow mutably. Deliberately bounded on the plain
+    /// `OutEdgeCollection`, not `MutableOutEdgeCollection`: adding or removing
+    /// edges can invalidate other handles derived from the same borrow, so
+    /// that's only available by consuming the original borrow via
+    /// `to_out_edges`, never through this shorter-lived reborrow.
+    type MutOutEdges<'s>: OutEdgeCollection<'s> where Self: 's;
+    /// The incoming-edge counterpart of `MutOutEdges`.
+    type MutInEdges<'s>: InEdgeCollection<'s> where Self: 's;
+
+    /// Adds a new, edgeless vertex holding `data` to the graph, returning a
+    /// mutable handle to it. Takes `&'a mut self` because this changes the
+    /// graph's vertex set itself, rather than the topology reachable from an
+    /// existing vertex.
+    fn add_vertex(&'a mut self, data: Self::VertexData) -> Self::MutVertex;
+
+    /// Removes `vertex`, and every edge incident to it, from the graph.
+    /// Consumes `vertex` since it no longer refers to anything afterwards.
+    fn remove_vertex(&'a mut self, vertex: Self::MutVertex);
 }
 
 pub trait Vertex<'a>: Sized {
@@ -17,17 +45,25 @@ pub trait Vertex<'a>: Sized {
 
     fn data(&self) -> &<<Self as Vertex<'a>>::Types as GraphTypes>::VertexData;
 
+    /// A `Copy` handle identifying this vertex, usable as the `target` of
+    /// `MutableOutEdgeCollection::add_edge` once this handle's own borrow has
+    /// ended, e.g. after a freshly
+    /// `add_vertex`-ed vertex has been dropped or passed to `to_out_edges`.
+    fn descriptor(&self) -> <<Self as Vertex<'a>>::Types as GraphTypes>::VertexDescriptor;
+
     /// Since we have mutable access to the underlying graph, we can get a
     /// mutable borrow of its actual contents.
     fn data_mut(&mut self) -> &mut <<Self as Vertex<'a>>::Types as GraphTypes>::VertexData;
 
     /// Consumes `self` entirely and passes the underlying borrow to a list of
     /// outgoing edges.
-    fn to_out_edges(self) -> <<Self as Vertex<'a>>::Types as MutTypes<'a>>::MutOutEdgeCollection;
+    fn to_out_edges(self) -> <<Self as Vertex<'a>>::Types as MutTypes<'a>>::MutOutEdgeCollection
+    where <Self::Types as GraphTypes>::TraversalCategory: IncidenceGraph;
 
     /// Consumes `self` entirely and passes the underlying borrow to a list of
     /// incomign edges.
-    fn to_in_edges(self) -> <<Self as Vertex<'a>>::Types as MutTypes<'a>>::MutInEdgeCollection;
+    fn to_in_edges(self) -> <<Self as Vertex<'a>>::Types as MutTypes<'a>>::MutInEdgeCollection
+    where <Self::Types as GraphTypes>::TraversalCategory: BidirectionalGraph;
 
     /// Vertex and its kindred in the mut_types module are backed by a mutable
     /// borrow of a graph structure. This means that it is not possible to have
@@ -55,45 +91,41 @@ pub trait Vertex<'a>: Sized {
     /// we create an instance of a type that implements
     /// `nav_types::OutEdgeCollection<'s>`, for the lifetime `'s` that is
     /// created for the borrow of vertex when it is passed as the `&self`
-    /// parameter of out_edges. Unfortunately for us the return type of
-    /// `out_edges()` needs to include this lifetime, and it is not known
-    /// statically. A different lifetime must be created for each distinct
-    /// borrow of a `mut_types::Vertex` impl. To be able to do this, we need to
-    /// be able to apply the type `nav_types::OutEdgeCollection` to the lifetime
-    /// `'s`. This requires polymorphic types that are functions of lifetimes.
-    ///
-    /// For simple relationships between a lifetime that is already bound (like
-    /// the `'a` of `Vertex<'a>`) and on that is created in a new scope (like
-    /// the `'s` of `fn out_edges<'s>(&'s self)`), we know that `'a` outlives
-    /// `'s`, so it looks a lot like all the information needed to generate the
-    /// appropriate type (like `Vertex<'s>`) is available. Unfortunately, the
-    /// compiler balks with the error:
-    ///
-    /// ```txt
-    /// error[E0308]: mismatched types
-    /// fn out_edges<'s>(&'s self) -> <<Self as Vertex<'s>>::Types as NavTypes<'s>>::NavOutEdgeCollection where 'a: 's;
-    ///
-    /// lifetime mismatch
-    ///
-    /// note: expected type `mut_types::MutTypes<'a>`
-    /// note:    found type `mut_types::MutTypes<'s>`
-    /// note: the lifetime 's as defined on unknown free region bounded by scope CodeExtent(85/DestructionScope(309))...
-    /// note: ...does not necessarily outlive the lifetime 'a as defined on unknown free region bounded by scope CodeExtent(85/DestructionScope(309))
-    /// ```
-    ///
-    /// This is the case even when we state that 'a outlives 's, as in:
-    ///
-    /// ```rust,ignore
-    /// fn out_edges<'s>(&'s self) -> <<Self as Vertex<'s>>::Types as NavTypes<'s>>::NavOutEdgeCollection where 'a: 's;
-    /// fn in_edges<'s>(&'s self) -> <<Self as Vertex<'s>>::Types as NavTypes<'s>>::NavInEdgeCollection where 'a: 's;
-    /// fn out_edges_mut<'s>(&'s mut self) -> <<Self as Vertex<'s>>::Types as MutTypes<'s>>::MutOutEdgeCollection where 'a: 's;
-    /// fn in_edges_mut<'s>(&'s mut self) -> <<Self as Vertex<'s>>::Types as MutTypes<'s>>::MutInEdgeCollection where 'a: 's;
-    /// ```
-    fn out_edges(&self);
+    /// parameter of out_edges. The return type of `out_edges()` needs to
+    /// include this lifetime, and a different lifetime must be produced for
+    /// each distinct borrow of a `mut_types::Vertex` impl. That requires a
+    /// type that is itself a function of a lifetime, which is exactly what a
+    /// generic associated type gives us: `MutTypes::NavOutEdges<'s>` (and its
+    /// siblings `NavInEdges`, `MutOutEdges`, `MutInEdges`) map the reborrow
+    /// lifetime `'s` to the appropriate collection type, with `Self: 's`
+    /// standing in for the `'a: 's` relationship between the vertex's own
+    /// lifetime and the shorter reborrow.
+    fn out_edges<'s>(&'s self) -> <Self::Types as MutTypes<'a>>::NavOutEdges<'s>
+    where 'a: 's, <Self::Types as GraphTypes>::TraversalCategory: IncidenceGraph;
+
+    /// The incoming-edge counterpart of `out_edges`.
+    fn in_edges<'s>(&'s self) -> <Self::Types as MutTypes<'a>>::NavInEdges<'s>
+    where 'a: 's, <Self::Types as GraphTypes>::TraversalCategory: BidirectionalGraph;
+
+    /// Like `out_edges`, but the reborrow is mutable: the returned collection
+    /// implements `mut_types::OutEdgeCollection`, and `self` stays mutably
+    /// borrowed while it is live. That trait offers no methods, so neither
+    /// edge data nor graph topology can be changed through it.
+    fn out_edges_mut<'s>(&'s mut self) -> <Self::Types as MutTypes<'a>>::MutOutEdges<'s>
+    where 'a: 's, <Self::Types as GraphTypes>::TraversalCategory: IncidenceGraph;
+
+    /// The incoming-edge counterpart of `out_edges_mut`.
+    fn in_edges_mut<'s>(&'s mut self) -> <Self::Types as MutTypes<'a>>::MutInEdges<'s>
+    where 'a: 's, <Self::Types as GraphTypes>::TraversalCategory: BidirectionalGraph;
 }
 
 pub trait Edge<'a>: Sized {
     type Types: MutTypes<'a, MutEdge=Self>;
+
+    /// The edge counterpart of `Vertex::descriptor`, usable as the `edge`
+    /// argument of `MutableOutEdgeCollection::remove_edge` once this handle's
+    /// own borrow has ended.
+    fn descriptor(&self) -> <<Self as Edge<'a>>::Types as GraphTypes>::EdgeDescriptor;
 }
 
 pub trait InEdgeCollection<'a>: Sized {
@@ -102,6 +134,389 @@ pub trait InEdgeCollection<'a>: Sized {
 }
 
 pub trait OutEdgeCollection<'a>: Sized {
-    type Types: MutTypes<'a, MutOutEdgeCollection=Self>;
+    type Types: MutTypes<'a>;
     type Iter: BoundedIterator<'a>;
 }
+
+/// Structural mutation of an out-edge collection: adding or removing edges.
+/// Implemented only by the concrete top-level `MutTypes::MutOutEdgeCollection`
+/// obtained by consuming a vertex via `Vertex::to_out_edges`, never by the
+/// `MutOutEdges<'s>` reborrow GAT (which is bound on the plain
+/// `OutEdgeCollection` above): a mutation here can invalidate other handles
+/// derived from the same underlying borrow, and a reborrow's shorter lifetime
+/// `'s` has no right to do that -- only the consumed, original borrow does.
+pub trait MutableOutEdgeCollection<'a>: OutEdgeCollection<'a>
+where
+    <Self as OutEdgeCollection<'a>>::Types: MutTypes<'a, MutOutEdgeCollection = Self>,
+{
+    /// Adds a new edge from this collection's source vertex to `target`,
+    /// holding `data`, to the graph. `target` is a lightweight
+    /// `VertexDescriptor` rather than a full `MutVertex` handle, since a
+    /// second live mutable handle to the target vertex would violate the
+    /// single-live-mutable-borrow invariant this module documents.
+    ///
+    /// Consumes `self` and returns it back alongside the new edge's handle,
+    /// re-threading the underlying mutable borrow the same way
+    /// `Vertex::to_out_edges` does, since adding an edge can invalidate other
+    /// handles derived from the same borrow.
+    fn add_edge(
+        self,
+        target: <Self::Types as GraphTypes>::VertexDescriptor,
+        data: <Self::Types as GraphTypes>::EdgeData,
+    ) -> (Self, <Self::Types as MutTypes<'a>>::MutEdge);
+
+    /// Removes the edge identified by `edge` from this collection. Consumes
+    /// and returns `self` for the same re-threading reason as `add_edge`.
+    fn remove_edge(self, edge: <Self::Types as GraphTypes>::EdgeDescriptor) -> Self;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Directed, AllowParallelEdges, GraphTypes, IncidenceGraph, BoundedIterator};
+
+    /// A mutable adjacency-list graph: `out_adjacency[v]` holds the indices
+    /// (into `edges`) of `v`'s outgoing edges. Vertices and edges are only
+    /// ever appended, so existing indices stay valid once assigned.
+    #[derive(Debug)]
+    struct TestGraph {
+        vertex_data: Vec<&'static str>,
+        edges: Vec<(usize, usize, u32)>,
+        out_adjacency: Vec<Vec<usize>>,
+    }
+
+    impl TestGraph {
+        fn new(vertex_data: Vec<&'static str>) -> Self {
+            let out_adjacency = vec![Vec::new(); vertex_data.len()];
+            TestGraph { vertex_data, edges: Vec::new(), out_adjacency }
+        }
+    }
+
+    /// Marker for `TestGraph::TraversalCategory`: only outgoing edges are
+    /// enumerable.
+    struct TestTraversal;
+    impl IncidenceGraph for TestTraversal {}
+
+    impl GraphTypes for TestGraph {
+        type VertexData = &'static str;
+        type EdgeData = u32;
+        type DirectedCategory = Directed;
+        type EdgeParallelCategory = AllowParallelEdges;
+        type TraversalCategory = TestTraversal;
+        type VertexDescriptor = usize;
+        type EdgeDescriptor = usize;
+        type VertexIndexType = usize;
+        type DegreeSizeType = usize;
+    }
+
+    /// An iterator that is never actually driven in these tests; it exists
+    /// only to give the vestigial `Iter` associated types below a concrete
+    /// value.
+    struct DummyIter;
+
+    impl Iterator for DummyIter {
+        type Item = ();
+
+        fn next(&mut self) -> Option<()> {
+            None
+        }
+    }
+
+    impl<'a> BoundedIterator<'a> for DummyIter {}
+
+    // -- Read-only views, needed only because `MutTypes: NavTypes` requires
+    // a full `nav_types` implementation to exist, even though this test
+    // never drives it. Reused both as `NavTypes::NavOutEdgeCollection` and as
+    // the `NavOutEdges<'s>`/`NavInEdges<'s>` reborrow GATs, the same way
+    // `MutInEdges<'s>` reuses `MutInEdgesImpl` across lifetimes elsewhere in
+    // this file (`MutOutEdges<'s>`, by contrast, is its own distinct type).
+
+    #[derive(Clone, Copy)]
+    struct NavVertexView<'a> {
+        graph: &'a TestGraph,
+        index: usize,
+    }
+
+    #[derive(Clone, Copy)]
+    struct NavEdgeView<'a> {
+        graph: &'a TestGraph,
+        edge_index: usize,
+    }
+
+    struct NavOutEdgesView<'a> {
+        graph: &'a TestGraph,
+        vertex: usize,
+    }
+
+    struct NavEdgeIter<'a> {
+        graph: &'a TestGraph,
+        remaining: std::slice::Iter<'a, usize>,
+    }
+
+    impl<'a> Iterator for NavEdgeIter<'a> {
+        type Item = NavEdgeView<'a>;
+
+        fn next(&mut self) -> Option<NavEdgeView<'a>> {
+            self.remaining.next().map(|&edge_index| NavEdgeView { graph: self.graph, edge_index })
+        }
+    }
+
+    impl<'a> BoundedIterator<'a> for NavEdgeIter<'a> {}
+
+    impl<'a> crate::nav_types::NavTypes<'a> for TestGraph {
+        type NavVertex = NavVertexView<'a>;
+        type NavEdge = NavEdgeView<'a>;
+        type NavInEdgeCollection = NavOutEdgesView<'a>;
+        type NavOutEdgeCollection = NavOutEdgesView<'a>;
+    }
+
+    impl<'a> crate::nav_types::Vertex<'a> for NavVertexView<'a> {
+        type Types = TestGraph;
+
+        fn data(&self) -> &'a &'static str {
+            &self.graph.vertex_data[self.index]
+        }
+
+        fn index(&self) -> usize {
+            self.index
+        }
+
+        fn descriptor(&self) -> usize {
+            self.index
+        }
+
+        fn out_edges(&self) -> NavOutEdgesView<'a> {
+            NavOutEdgesView { graph: self.graph, vertex: self.index }
+        }
+
+        fn in_edges(&self) -> NavOutEdgesView<'a> {
+            unimplemented!("TestGraph::TraversalCategory does not implement BidirectionalGraph")
+        }
+    }
+
+    impl<'a> crate::nav_types::Edge<'a> for NavEdgeView<'a> {
+        type Types = TestGraph;
+
+        fn data(&self) -> &'a u32 {
+            &self.graph.edges[self.edge_index].2
+        }
+
+        fn descriptor(&self) -> usize {
+            self.edge_index
+        }
+
+        fn source(&self) -> NavVertexView<'a> {
+            NavVertexView { graph: self.graph, index: self.graph.edges[self.edge_index].0 }
+        }
+
+        fn target(&self) -> NavVertexView<'a> {
+            NavVertexView { graph: self.graph, index: self.graph.edges[self.edge_index].1 }
+        }
+    }
+
+    impl<'a> crate::nav_types::OutEdgeCollection<'a> for NavOutEdgesView<'a> {
+        type Types = TestGraph;
+        type Iter = NavEdgeIter<'a>;
+
+        fn len(&self) -> usize {
+            self.graph.out_adjacency[self.vertex].len()
+        }
+
+        fn source(&self) -> NavVertexView<'a> {
+            NavVertexView { graph: self.graph, index: self.vertex }
+        }
+
+        fn iter(&self) -> NavEdgeIter<'a> {
+            NavEdgeIter { graph: self.graph, remaining: self.graph.out_adjacency[self.vertex].iter() }
+        }
+    }
+
+    /// Never actually driven, since `TestGraph::TraversalCategory` doesn't
+    /// implement `BidirectionalGraph`; exists only to satisfy
+    /// `NavTypes::NavInEdgeCollection`'s bound.
+    impl<'a> crate::nav_types::InEdgeCollection<'a> for NavOutEdgesView<'a> {
+        type Types = TestGraph;
+        type Iter = NavEdgeIter<'a>;
+
+        fn len(&self) -> usize {
+            self.graph.out_adjacency[self.vertex].len()
+        }
+
+        fn target(&self) -> NavVertexView<'a> {
+            NavVertexView { graph: self.graph, index: self.vertex }
+        }
+
+        fn iter(&self) -> NavEdgeIter<'a> {
+            NavEdgeIter { graph: self.graph, remaining: self.graph.out_adjacency[self.vertex].iter() }
+        }
+    }
+
+    struct MutVertexImpl<'a> {
+        graph: &'a mut TestGraph,
+        index: usize,
+    }
+
+    /// The edge handle `MutableOutEdgeCollection::add_edge` hands back. Holds
+    /// no borrow of the graph at all, since `mut_types::Edge` only ever asks
+    /// it for its own `descriptor()`.
+    struct MutEdgeImpl<'a> {
+        edge_index: usize,
+        _marker: std::marker::PhantomData<&'a mut TestGraph>,
+    }
+
+    /// The top-level out-edge collection returned by `Vertex::to_out_edges`.
+    /// This is the only type that implements `MutableOutEdgeCollection`: it
+    /// holds the vertex's entire original mutable borrow (consumed, not
+    /// reborrowed), so adding or removing an edge here can't leave any other
+    /// live handle dangling.
+    struct MutOutEdgesImpl<'a> {
+        graph: &'a mut TestGraph,
+        source: usize,
+    }
+
+    /// The `MutOutEdges<'s>` reborrow returned by `Vertex::out_edges_mut`.
+    /// Deliberately a distinct type from `MutOutEdgesImpl` that implements
+    /// only the base `OutEdgeCollection`: there is no `add_edge`/`remove_edge`
+    /// to even attempt to call through it, which is exactly the invariant
+    /// this test exists to demonstrate.
+    struct MutOutEdgesReborrow<'a> {
+        #[allow(dead_code)]
+        graph: &'a mut TestGraph,
+        #[allow(dead_code)]
+        source: usize,
+    }
+
+    struct MutInEdgesImpl<'a> {
+        _marker: std::marker::PhantomData<&'a mut TestGraph>,
+    }
+
+    impl<'a> MutTypes<'a> for TestGraph {
+        type MutVertex = MutVertexImpl<'a>;
+        type MutEdge = MutEdgeImpl<'a>;
+        type MutInEdgeCollection = MutInEdgesImpl<'a>;
+        type MutOutEdgeCollection = MutOutEdgesImpl<'a>;
+
+        type NavOutEdges<'s> = NavOutEdgesView<'s> where Self: 's;
+        type NavInEdges<'s> = NavOutEdgesView<'s> where Self: 's;
+        type MutOutEdges<'s> = MutOutEdgesReborrow<'s> where Self: 's;
+        type MutInEdges<'s> = MutInEdgesImpl<'s> where Self: 's;
+
+        fn add_vertex(&'a mut self, data: &'static str) -> MutVertexImpl<'a> {
+            self.vertex_data.push(data);
+            self.out_adjacency.push(Vec::new());
+            let index = self.vertex_data.len() - 1;
+            MutVertexImpl { graph: self, index }
+        }
+
+        fn remove_vertex(&'a mut self, _vertex: MutVertexImpl<'a>) {
+            unimplemented!("not exercised by this test")
+        }
+    }
+
+    impl<'a> Vertex<'a> for MutVertexImpl<'a> {
+        type Types = TestGraph;
+
+        fn data(&self) -> &&'static str {
+            &self.graph.vertex_data[self.index]
+        }
+
+        fn descriptor(&self) -> usize {
+            self.index
+        }
+
+        fn data_mut(&mut self) -> &mut &'static str {
+            &mut self.graph.vertex_data[self.index]
+        }
+
+        fn to_out_edges(self) -> MutOutEdgesImpl<'a> {
+            MutOutEdgesImpl { graph: self.graph, source: self.index }
+        }
+
+        fn to_in_edges(self) -> MutInEdgesImpl<'a> {
+            unimplemented!("TestGraph::TraversalCategory does not implement BidirectionalGraph")
+        }
+
+        fn out_edges<'s>(&'s self) -> NavOutEdgesView<'s> where 'a: 's {
+            NavOutEdgesView { graph: self.graph, vertex: self.index }
+        }
+
+        fn in_edges<'s>(&'s self) -> NavOutEdgesView<'s> where 'a: 's {
+            unimplemented!("TestGraph::TraversalCategory does not implement BidirectionalGraph")
+        }
+
+        fn out_edges_mut<'s>(&'s mut self) -> MutOutEdgesReborrow<'s> where 'a: 's {
+            MutOutEdgesReborrow { graph: self.graph, source: self.index }
+        }
+
+        fn in_edges_mut<'s>(&'s mut self) -> MutInEdgesImpl<'s> where 'a: 's {
+            unimplemented!("TestGraph::TraversalCategory does not implement BidirectionalGraph")
+        }
+    }
+
+    impl<'a> Edge<'a> for MutEdgeImpl<'a> {
+        type Types = TestGraph;
+
+        fn descriptor(&self) -> usize {
+            self.edge_index
+        }
+    }
+
+    impl<'a> OutEdgeCollection<'a> for MutOutEdgesImpl<'a> {
+        type Types = TestGraph;
+        type Iter = DummyIter;
+    }
+
+    impl<'a> MutableOutEdgeCollection<'a> for MutOutEdgesImpl<'a> {
+        fn add_edge(self, target: usize, data: u32) -> (Self, MutEdgeImpl<'a>) {
+            self.graph.edges.push((self.source, target, data));
+            let edge_index = self.graph.edges.len() - 1;
+            self.graph.out_adjacency[self.source].push(edge_index);
+            let source = self.source;
+            let graph = self.graph;
+            (
+                MutOutEdgesImpl { graph, source },
+                MutEdgeImpl { edge_index, _marker: std::marker::PhantomData },
+            )
+        }
+
+        fn remove_edge(self, edge: usize) -> Self {
+            self.graph.out_adjacency[self.source].retain(|&e| e != edge);
+            self
+        }
+    }
+
+    impl<'a> OutEdgeCollection<'a> for MutOutEdgesReborrow<'a> {
+        type Types = TestGraph;
+        type Iter = DummyIter;
+    }
+
+    impl<'a> InEdgeCollection<'a> for MutInEdgesImpl<'a> {
+        type Types = TestGraph;
+        type Iter = DummyIter;
+    }
+
+    #[test]
+    fn add_vertex_reborrow_then_add_edge_mutates_the_graph() {
+        let mut graph = TestGraph::new(vec!["a"]);
+
+        {
+            let mut vertex = graph.add_vertex("b");
+            assert_eq!(vertex.descriptor(), 1);
+
+            // A short mutable reborrow: its type implements only the base
+            // `OutEdgeCollection`, not `MutableOutEdgeCollection`, so there
+            // is no `add_edge`/`remove_edge` reachable here even while
+            // `vertex` itself is still alive.
+            {
+                let _reborrow = vertex.out_edges_mut();
+            }
+
+            let out_edges = vertex.to_out_edges();
+            let (_out_edges, new_edge) = out_edges.add_edge(0, 7);
+            assert_eq!(new_edge.descriptor(), 0);
+        }
+
+        assert_eq!(graph.edges, vec![(1, 0, 7)]);
+        assert_eq!(graph.out_adjacency[1], vec![0]);
+    }
+}
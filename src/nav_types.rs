@@ -5,7 +5,7 @@
 //! These traits may be thought of as fat pointers into some graph structure,
 //! augmented operations to navigate it.
 
-use super::{GraphTypes, BoundedIterator};
+use super::{GraphTypes, BidirectionalGraph, BoundedIterator, IncidenceGraph};
 
 pub trait NavTypes<'a>: GraphTypes {
     type NavVertex: Vertex<'a, Types=Self>;
@@ -16,12 +16,26 @@ pub trait NavTypes<'a>: GraphTypes {
 
 pub trait Vertex<'a>: Sized {
     type Types: NavTypes<'a, NavVertex=Self>;
-    
+
     fn data(&self) -> &'a <<Self as Vertex<'a>>::Types as GraphTypes>::VertexData;
 
-    fn out_edges(&self) -> <<Self as Vertex<'a>>::Types as NavTypes<'a>>::NavOutEdgeCollection;
+    /// A lightweight, `Copy`, hashable identifier for this vertex, stable for
+    /// as long as the graph's topology is unchanged. Algorithms (see the
+    /// `algo` module) use this to track per-vertex state externally, without
+    /// requiring mutable access to the graph.
+    fn index(&self) -> <<Self as Vertex<'a>>::Types as GraphTypes>::VertexIndexType;
+
+    /// A `Copy` handle identifying this vertex, distinct from `index()`: a
+    /// `VertexDescriptor` may be passed to `mut_types::MutableOutEdgeCollection::
+    /// add_edge` to wire up an edge to this vertex, whereas `VertexIndexType`
+    /// is only meant for indexing into dense external storage.
+    fn descriptor(&self) -> <<Self as Vertex<'a>>::Types as GraphTypes>::VertexDescriptor;
 
-    fn in_edges(&self) -> <<Self as Vertex<'a>>::Types as NavTypes<'a>>::NavInEdgeCollection;
+    fn out_edges(&self) -> <<Self as Vertex<'a>>::Types as NavTypes<'a>>::NavOutEdgeCollection
+    where <Self::Types as GraphTypes>::TraversalCategory: IncidenceGraph;
+
+    fn in_edges(&self) -> <<Self as Vertex<'a>>::Types as NavTypes<'a>>::NavInEdgeCollection
+    where <Self::Types as GraphTypes>::TraversalCategory: BidirectionalGraph;
 }
 
 pub trait Edge<'a>: Sized {
@@ -29,6 +43,9 @@ pub trait Edge<'a>: Sized {
 
     fn data(&self) -> &'a <<Self as Edge<'a>>::Types as GraphTypes>::EdgeData;
 
+    /// The edge counterpart of `Vertex::descriptor`.
+    fn descriptor(&self) -> <<Self as Edge<'a>>::Types as GraphTypes>::EdgeDescriptor;
+
     fn source(&self) -> <<Self as Edge<'a>>::Types as NavTypes<'a>>::NavVertex;
 
     fn target(&self) -> <<Self as Edge<'a>>::Types as NavTypes<'a>>::NavVertex;
@@ -36,7 +53,15 @@ pub trait Edge<'a>: Sized {
 
 pub trait InEdgeCollection<'a>: Sized {
     type Types: NavTypes<'a, NavInEdgeCollection=Self>;
-    type Iter: BoundedIterator<'a, Item=<<Self as InEdgeCollection<'a>>::Types as NavTypes<'a>>::NavEdge>;
+    /// Deliberately a plain `BoundedIterator`, not an iterator whose `Item` is
+    /// a GAT indexed by the reborrow passed to `next`: `NavEdge` already
+    /// carries its own borrow of the graph at `'a`, so it never needs to
+    /// additionally borrow from the iterator's own reborrow; pinning `Item`
+    /// to such a GAT here would only force every `next()` call to hold the
+    /// iterator borrowed for the iterator's whole lifetime, making a second
+    /// call a conflicting reborrow, with no actual lending benefit to show
+    /// for it.
+    type Iter: BoundedIterator<'a, Item = <<Self as InEdgeCollection<'a>>::Types as NavTypes<'a>>::NavEdge>;
 
     fn len(&self) -> usize;
 
@@ -49,7 +74,8 @@ pub trait InEdgeCollection<'a>: Sized {
 
 pub trait OutEdgeCollection<'a>: Sized {
     type Types: NavTypes<'a, NavOutEdgeCollection=Self>;
-    type Iter: BoundedIterator<'a, Item=<<Self as OutEdgeCollection<'a>>::Types as NavTypes<'a>>::NavEdge>;
+    /// See the note on `InEdgeCollection::Iter`.
+    type Iter: BoundedIterator<'a, Item = <<Self as OutEdgeCollection<'a>>::Types as NavTypes<'a>>::NavEdge>;
 
     fn len(&self) -> usize;
 
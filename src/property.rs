@@ -0,0 +1,126 @@
+//! Boost-style separation of graph structure from associated properties.
+//! Rather than threading every piece of data an algorithm needs (weights,
+//! distances, predecessors, ...) through `GraphTypes::VertexData`/`EdgeData`,
+//! algorithms take `PropertyMap` parameters keyed by the graph's lightweight
+//! `VertexDescriptor`/`EdgeDescriptor` handles (or any other stable,
+//! hashable identity, such as `VertexIndexType`). This lets several
+//! annotated analyses run concurrently over the same immutable graph
+//! borrow with independent property maps, which the `nav_types`
+//! multiple-coexisting-instances guarantee already permits.
+
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// A read-only mapping from a key -- typically a `GraphTypes::VertexDescriptor`
+/// or `EdgeDescriptor`, or a `GraphTypes::VertexIndexType` -- to an associated
+/// value.
+pub trait PropertyMap<Key, Value> {
+    fn get(&self, key: Key) -> &Value;
+}
+
+/// A `PropertyMap` that can also be written to, e.g. to record a distance or
+/// predecessor discovered during a traversal.
+pub trait MutablePropertyMap<Key, Value>: PropertyMap<Key, Value> {
+    fn put(&mut self, key: Key, value: Value);
+}
+
+/// A `PropertyMap` that reads a named field out of a graph's existing
+/// `VertexData`/`EdgeData` via a user-supplied accessor, rather than storing
+/// any data of its own. Useful when the property an algorithm wants (e.g. an
+/// edge weight) is already present on the graph, so there is no need to
+/// duplicate it into an `ExternalPropertyMap`.
+pub struct InternalPropertyMap<'g, G, Key, Value: 'g, F>
+where
+    F: Fn(&'g G, Key) -> &'g Value,
+{
+    graph: &'g G,
+    accessor: F,
+    _marker: std::marker::PhantomData<fn(Key) -> Value>,
+}
+
+impl<'g, G, Key, Value: 'g, F> InternalPropertyMap<'g, G, Key, Value, F>
+where
+    F: Fn(&'g G, Key) -> &'g Value,
+{
+    pub fn new(graph: &'g G, accessor: F) -> Self {
+        InternalPropertyMap {
+            graph,
+            accessor,
+            _marker: std::marker::PhantomData,
+        }
+    }
+}
+
+impl<'g, G, Key, Value: 'g, F> PropertyMap<Key, Value> for InternalPropertyMap<'g, G, Key, Value, F>
+where
+    F: Fn(&'g G, Key) -> &'g Value,
+{
+    fn get(&self, key: Key) -> &Value {
+        (self.accessor)(self.graph, key)
+    }
+}
+
+/// A `PropertyMap` backed by a `HashMap`, for auxiliary per-vertex or
+/// per-edge data (distances, predecessors, weights, ...) that isn't part of
+/// the graph's own `VertexData`/`EdgeData`.
+pub struct ExternalPropertyMap<Key, Value> {
+    map: HashMap<Key, Value>,
+}
+
+impl<Key: Eq + Hash, Value> ExternalPropertyMap<Key, Value> {
+    pub fn new() -> Self {
+        ExternalPropertyMap { map: HashMap::new() }
+    }
+}
+
+impl<Key: Eq + Hash, Value> Default for ExternalPropertyMap<Key, Value> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<Key: Eq + Hash, Value> PropertyMap<Key, Value> for ExternalPropertyMap<Key, Value> {
+    fn get(&self, key: Key) -> &Value {
+        self.map
+            .get(&key)
+            .expect("no value recorded for key in ExternalPropertyMap")
+    }
+}
+
+impl<Key: Eq + Hash, Value> MutablePropertyMap<Key, Value> for ExternalPropertyMap<Key, Value> {
+    fn put(&mut self, key: Key, value: Value) {
+        self.map.insert(key, value);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Weights {
+        by_index: Vec<u32>,
+    }
+
+    fn weight_at(graph: &Weights, index: usize) -> &u32 {
+        &graph.by_index[index]
+    }
+
+    #[test]
+    fn internal_property_map_reads_through_the_accessor() {
+        let weights = Weights { by_index: vec![10, 20, 30] };
+        let map = InternalPropertyMap::new(&weights, weight_at);
+
+        assert_eq!(*map.get(0), 10);
+        assert_eq!(*map.get(2), 30);
+    }
+
+    #[test]
+    fn external_property_map_reads_back_what_was_put() {
+        let mut map = ExternalPropertyMap::new();
+        map.put(0usize, "a");
+        map.put(1usize, "b");
+
+        assert_eq!(*map.get(0), "a");
+        assert_eq!(*map.get(1), "b");
+    }
+}